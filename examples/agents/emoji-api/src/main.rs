@@ -1,265 +1,459 @@
-use std::sync::LazyLock;
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, LazyLock, RwLock};
 
-use axum::{extract::Query, http::StatusCode, response::IntoResponse, Json, Router};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json, Router,
+};
+use lru::LruCache;
 use serde::{Deserialize, Serialize};
 use strsim::jaro_winkler;
 use utoipa::{IntoParams, OpenApi, ToSchema};
 use utoipa_swagger_ui::SwaggerUi;
 
+/// Maximum number of runtime-registered custom emoji kept before the LRU
+/// cache evicts the least recently used entry.
+const CUSTOM_EMOJI_CAPACITY: usize = 256;
+
+/// Shared, runtime-mutable registry of custom/shortcode emoji, consulted by
+/// [`rank_matches`] before the static [`EMOJI_MAP`].
+type CustomEmojiStore = Arc<RwLock<LruCache<String, String>>>;
+
+#[derive(Clone)]
+struct AppState {
+    custom_emoji: CustomEmojiStore,
+}
+
+impl AppState {
+    fn new() -> Self {
+        Self {
+            custom_emoji: Arc::new(RwLock::new(LruCache::new(
+                NonZeroUsize::new(CUSTOM_EMOJI_CAPACITY).unwrap(),
+            ))),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Emoji map
 // ---------------------------------------------------------------------------
 
-static EMOJI_MAP: LazyLock<Vec<(&str, &str)>> = LazyLock::new(|| {
+static EMOJI_MAP: LazyLock<Vec<(&str, &[&str])>> = LazyLock::new(|| {
     vec![
         // Food & Drink
-        ("taco", "ğŸŒ®"),
-        ("burrito", "ğŸŒ¯"),
-        ("pizza", "ğŸ•"),
-        ("hamburger", "ğŸ”"),
-        ("hotdog", "ğŸŒ­"),
-        ("fries", "ğŸŸ"),
-        ("popcorn", "ğŸ¿"),
-        ("sandwich", "ğŸ¥ª"),
-        ("bagel", "ğŸ¥¯"),
-        ("pretzel", "ğŸ¥¨"),
-        ("cheese", "ğŸ§€"),
-        ("egg", "ğŸ¥š"),
-        ("bacon", "ğŸ¥“"),
-        ("steak", "ğŸ¥©"),
-        ("chicken", "ğŸ—"),
-        ("shrimp", "ğŸ¦"),
-        ("sushi", "ğŸ£"),
-        ("ramen", "ğŸœ"),
-        ("spaghetti", "ğŸ"),
-        ("rice", "ğŸš"),
-        ("curry", "ğŸ›"),
-        ("dumpling", "ğŸ¥Ÿ"),
-        ("cookie", "ğŸª"),
-        ("cake", "ğŸ‚"),
-        ("pie", "ğŸ¥§"),
-        ("chocolate", "ğŸ«"),
-        ("candy", "ğŸ¬"),
-        ("lollipop", "ğŸ­"),
-        ("donut", "ğŸ©"),
-        ("icecream", "ğŸ¦"),
-        ("coffee", "â˜•"),
-        ("tea", "ğŸµ"),
-        ("beer", "ğŸº"),
-        ("wine", "ğŸ·"),
-        ("cocktail", "ğŸ¸"),
-        ("juice", "ğŸ§ƒ"),
-        ("milk", "ğŸ¥›"),
-        ("water", "ğŸ’§"),
-        ("apple", "ğŸ"),
-        ("banana", "ğŸŒ"),
-        ("orange", "ğŸŠ"),
-        ("lemon", "ğŸ‹"),
-        ("grape", "ğŸ‡"),
-        ("watermelon", "ğŸ‰"),
-        ("strawberry", "ğŸ“"),
-        ("peach", "ğŸ‘"),
-        ("cherry", "ğŸ’"),
-        ("pineapple", "ğŸ"),
-        ("coconut", "ğŸ¥¥"),
-        ("avocado", "ğŸ¥‘"),
-        ("broccoli", "ğŸ¥¦"),
-        ("carrot", "ğŸ¥•"),
-        ("corn", "ğŸŒ½"),
-        ("pepper", "ğŸŒ¶ï¸"),
-        ("mushroom", "ğŸ„"),
-        ("tomato", "ğŸ…"),
-        ("potato", "ğŸ¥”"),
-        ("onion", "ğŸ§…"),
-        ("garlic", "ğŸ§„"),
+        ("ğŸŒ®", &["taco"]),
+        ("ğŸŒ¯", &["burrito"]),
+        ("ğŸ•", &["pizza", "dog", "clock", "slice", "food"]),
+        ("ğŸ”", &["hamburger", "chicken"]),
+        ("ğŸŒ­", &["hotdog"]),
+        ("ğŸŸ", &["fries", "fish"]),
+        ("ğŸ¿", &["popcorn"]),
+        ("ğŸ¥ª", &["sandwich"]),
+        ("ğŸ¥¯", &["bagel"]),
+        ("ğŸ¥¨", &["pretzel"]),
+        ("ğŸ§€", &["cheese"]),
+        ("ğŸ¥š", &["egg"]),
+        ("ğŸ¥“", &["bacon"]),
+        ("ğŸ¥©", &["steak"]),
+        ("ğŸ—", &["chicken"]),
+        ("ğŸ¦", &["shrimp", "icecream", "lion", "bird", "lobster", "lizard", "rhino", "gorilla"]),
+        ("ğŸ£", &["sushi", "fishing"]),
+        ("ğŸœ", &["ramen", "ant"]),
+        ("ğŸ", &["spaghetti", "apple", "pineapple", "mouse", "snake", "bee", "ladybug", "volleyball", "gift", "flag"]),
+        ("ğŸš", &["rice"]),
+        ("ğŸ›", &["curry"]),
+        ("ğŸ¥Ÿ", &["dumpling"]),
+        ("ğŸª", &["cookie"]),
+        ("ğŸ‚", &["cake"]),
+        ("ğŸ¥§", &["pie"]),
+        ("ğŸ«", &["chocolate", "camel"]),
+        ("ğŸ¬", &["candy", "dolphin"]),
+        ("ğŸ­", &["lollipop"]),
+        ("ğŸ©", &["donut"]),
+        ("â˜•", &["coffee"]),
+        ("ğŸµ", &["tea", "music"]),
+        ("ğŸº", &["beer", "wolf"]),
+        ("ğŸ·", &["wine", "pig"]),
+        ("ğŸ¸", &["cocktail", "frog", "guitar"]),
+        ("ğŸ§ƒ", &["juice"]),
+        ("ğŸ¥›", &["milk"]),
+        ("ğŸ’§", &["water"]),
+        ("ğŸŒ", &["banana", "earth"]),
+        ("ğŸŠ", &["orange", "crocodile", "swimming"]),
+        ("ğŸ‹", &["lemon", "whale"]),
+        ("ğŸ‡", &["grape", "rabbit"]),
+        ("ğŸ‰", &["watermelon", "dragon", "rugby", "party"]),
+        ("ğŸ“", &["strawberry"]),
+        ("ğŸ‘", &["peach", "thumbsup", "thumbsdown", "clap"]),
+        ("ğŸ’", &["cherry", "monkey", "hockey", "gem"]),
+        ("ğŸ¥¥", &["coconut"]),
+        ("ğŸ¥‘", &["avocado"]),
+        ("ğŸ¥¦", &["broccoli"]),
+        ("ğŸ¥•", &["carrot"]),
+        ("ğŸŒ½", &["corn"]),
+        ("ğŸŒ¶ï¸", &["pepper"]),
+        ("ğŸ„", &["mushroom", "cow", "surfing"]),
+        ("ğŸ…", &["tomato", "medal"]),
+        ("ğŸ¥”", &["potato"]),
+        ("ğŸ§…", &["onion"]),
+        ("ğŸ§„", &["garlic"]),
         // Animals
-        ("dog", "ğŸ•"),
-        ("cat", "ğŸˆ"),
-        ("mouse", "ğŸ"),
-        ("rabbit", "ğŸ‡"),
-        ("fox", "ğŸ¦Š"),
-        ("bear", "ğŸ»"),
-        ("panda", "ğŸ¼"),
-        ("koala", "ğŸ¨"),
-        ("tiger", "ğŸ¯"),
-        ("lion", "ğŸ¦"),
-        ("cow", "ğŸ„"),
-        ("pig", "ğŸ·"),
-        ("frog", "ğŸ¸"),
-        ("monkey", "ğŸ’"),
-        ("chicken", "ğŸ”"),
-        ("penguin", "ğŸ§"),
-        ("bird", "ğŸ¦"),
-        ("eagle", "ğŸ¦…"),
-        ("owl", "ğŸ¦‰"),
-        ("duck", "ğŸ¦†"),
-        ("swan", "ğŸ¦¢"),
-        ("parrot", "ğŸ¦œ"),
-        ("flamingo", "ğŸ¦©"),
-        ("whale", "ğŸ‹"),
-        ("dolphin", "ğŸ¬"),
-        ("shark", "ğŸ¦ˆ"),
-        ("octopus", "ğŸ™"),
-        ("fish", "ğŸŸ"),
-        ("crab", "ğŸ¦€"),
-        ("lobster", "ğŸ¦"),
-        ("turtle", "ğŸ¢"),
-        ("snake", "ğŸ"),
-        ("lizard", "ğŸ¦"),
-        ("crocodile", "ğŸŠ"),
-        ("dinosaur", "ğŸ¦•"),
-        ("dragon", "ğŸ‰"),
-        ("butterfly", "ğŸ¦‹"),
-        ("bee", "ğŸ"),
-        ("ant", "ğŸœ"),
-        ("ladybug", "ğŸ"),
-        ("spider", "ğŸ•·ï¸"),
-        ("scorpion", "ğŸ¦‚"),
-        ("horse", "ğŸ´"),
-        ("unicorn", "ğŸ¦„"),
-        ("zebra", "ğŸ¦“"),
-        ("giraffe", "ğŸ¦’"),
-        ("elephant", "ğŸ˜"),
-        ("rhino", "ğŸ¦"),
-        ("hippo", "ğŸ¦›"),
-        ("camel", "ğŸ«"),
-        ("llama", "ğŸ¦™"),
-        ("gorilla", "ğŸ¦"),
-        ("sloth", "ğŸ¦¥"),
-        ("otter", "ğŸ¦¦"),
-        ("skunk", "ğŸ¦¨"),
-        ("hedgehog", "ğŸ¦”"),
-        ("bat", "ğŸ¦‡"),
-        ("wolf", "ğŸº"),
-        ("deer", "ğŸ¦Œ"),
+        ("ğŸˆ", &["cat", "football", "balloon"]),
+        ("ğŸ¦Š", &["fox"]),
+        ("ğŸ»", &["bear"]),
+        ("ğŸ¼", &["panda"]),
+        ("ğŸ¨", &["koala"]),
+        ("ğŸ¯", &["tiger"]),
+        ("ğŸ§", &["penguin"]),
+        ("ğŸ¦…", &["eagle"]),
+        ("ğŸ¦‰", &["owl"]),
+        ("ğŸ¦†", &["duck"]),
+        ("ğŸ¦¢", &["swan"]),
+        ("ğŸ¦œ", &["parrot"]),
+        ("ğŸ¦©", &["flamingo"]),
+        ("ğŸ¦ˆ", &["shark"]),
+        ("ğŸ™", &["octopus", "pray"]),
+        ("ğŸ¦€", &["crab"]),
+        ("ğŸ¢", &["turtle"]),
+        ("ğŸ¦•", &["dinosaur"]),
+        ("ğŸ¦‹", &["butterfly"]),
+        ("ğŸ•·ï¸", &["spider"]),
+        ("ğŸ¦‚", &["scorpion"]),
+        ("ğŸ´", &["horse"]),
+        ("ğŸ¦„", &["unicorn"]),
+        ("ğŸ¦“", &["zebra"]),
+        ("ğŸ¦’", &["giraffe"]),
+        ("ğŸ˜", &["elephant", "cool", "sad"]),
+        ("ğŸ¦›", &["hippo"]),
+        ("ğŸ¦™", &["llama"]),
+        ("ğŸ¦¥", &["sloth"]),
+        ("ğŸ¦¦", &["otter"]),
+        ("ğŸ¦¨", &["skunk"]),
+        ("ğŸ¦”", &["hedgehog"]),
+        ("ğŸ¦‡", &["bat"]),
+        ("ğŸ¦Œ", &["deer"]),
         // Nature & Weather
-        ("sun", "â˜€ï¸"),
-        ("moon", "ğŸŒ™"),
-        ("star", "â­"),
-        ("cloud", "â˜ï¸"),
-        ("rain", "ğŸŒ§ï¸"),
-        ("snow", "â„ï¸"),
-        ("lightning", "âš¡"),
-        ("tornado", "ğŸŒªï¸"),
-        ("rainbow", "ğŸŒˆ"),
-        ("fire", "ğŸ”¥"),
-        ("volcano", "ğŸŒ‹"),
-        ("ocean", "ğŸŒŠ"),
-        ("mountain", "ğŸ”ï¸"),
-        ("tree", "ğŸŒ³"),
-        ("flower", "ğŸŒ¸"),
-        ("rose", "ğŸŒ¹"),
-        ("tulip", "ğŸŒ·"),
-        ("sunflower", "ğŸŒ»"),
-        ("cactus", "ğŸŒµ"),
-        ("leaf", "ğŸƒ"),
-        ("clover", "ğŸ€"),
-        ("earth", "ğŸŒ"),
+        ("â˜€ï¸", &["sun"]),
+        ("ğŸŒ™", &["moon"]),
+        ("â­", &["star"]),
+        ("â˜ï¸", &["cloud"]),
+        ("ğŸŒ§ï¸", &["rain"]),
+        ("â„ï¸", &["snow"]),
+        ("âš¡", &["lightning"]),
+        ("ğŸŒªï¸", &["tornado"]),
+        ("ğŸŒˆ", &["rainbow"]),
+        ("ğŸ”¥", &["fire"]),
+        ("ğŸŒ‹", &["volcano"]),
+        ("ğŸŒŠ", &["ocean"]),
+        ("ğŸ”ï¸", &["mountain"]),
+        ("ğŸŒ³", &["tree"]),
+        ("ğŸŒ¸", &["flower"]),
+        ("ğŸŒ¹", &["rose"]),
+        ("ğŸŒ·", &["tulip"]),
+        ("ğŸŒ»", &["sunflower"]),
+        ("ğŸŒµ", &["cactus"]),
+        ("ğŸƒ", &["leaf", "running"]),
+        ("ğŸ€", &["clover", "basketball"]),
         // Emotions & People
-        ("smile", "ğŸ˜Š"),
-        ("laugh", "ğŸ˜‚"),
-        ("love", "â¤ï¸"),
-        ("heart", "â¤ï¸"),
-        ("kiss", "ğŸ˜˜"),
-        ("wink", "ğŸ˜‰"),
-        ("cool", "ğŸ˜"),
-        ("cry", "ğŸ˜¢"),
-        ("angry", "ğŸ˜ "),
-        ("sad", "ğŸ˜"),
-        ("fear", "ğŸ˜¨"),
-        ("surprise", "ğŸ˜²"),
-        ("think", "ğŸ¤”"),
-        ("sleep", "ğŸ˜´"),
-        ("sick", "ğŸ¤®"),
-        ("clown", "ğŸ¤¡"),
-        ("ghost", "ğŸ‘»"),
-        ("skull", "ğŸ’€"),
-        ("alien", "ğŸ‘½"),
-        ("robot", "ğŸ¤–"),
-        ("poop", "ğŸ’©"),
-        ("thumbsup", "ğŸ‘"),
-        ("thumbsdown", "ğŸ‘"),
-        ("clap", "ğŸ‘"),
-        ("wave", "ğŸ‘‹"),
-        ("pray", "ğŸ™"),
-        ("muscle", "ğŸ’ª"),
-        ("brain", "ğŸ§ "),
-        ("eyes", "ğŸ‘€"),
-        ("baby", "ğŸ‘¶"),
+        ("ğŸ˜Š", &["smile"]),
+        ("ğŸ˜‚", &["laugh"]),
+        ("â¤ï¸", &["love", "heart"]),
+        ("ğŸ˜˜", &["kiss"]),
+        ("ğŸ˜‰", &["wink"]),
+        ("ğŸ˜¢", &["cry"]),
+        ("ğŸ˜ ", &["angry"]),
+        ("ğŸ˜¨", &["fear"]),
+        ("ğŸ˜²", &["surprise"]),
+        ("ğŸ¤”", &["think"]),
+        ("ğŸ˜´", &["sleep"]),
+        ("ğŸ¤®", &["sick"]),
+        ("ğŸ¤¡", &["clown"]),
+        ("ğŸ‘»", &["ghost"]),
+        ("ğŸ’€", &["skull"]),
+        ("ğŸ‘½", &["alien"]),
+        ("ğŸ¤–", &["robot"]),
+        ("ğŸ’©", &["poop"]),
+        ("ğŸ‘‹", &["wave"]),
+        ("ğŸ’ª", &["muscle"]),
+        ("ğŸ§ ", &["brain"]),
+        ("ğŸ‘€", &["eyes"]),
+        ("ğŸ‘¶", &["baby"]),
         // Sports & Activities
-        ("soccer", "âš½"),
-        ("basketball", "ğŸ€"),
-        ("football", "ğŸˆ"),
-        ("baseball", "âš¾"),
-        ("tennis", "ğŸ¾"),
-        ("volleyball", "ğŸ"),
-        ("rugby", "ğŸ‰"),
-        ("golf", "â›³"),
-        ("bowling", "ğŸ³"),
-        ("hockey", "ğŸ’"),
-        ("skiing", "â›·ï¸"),
-        ("surfing", "ğŸ„"),
-        ("swimming", "ğŸŠ"),
-        ("cycling", "ğŸš´"),
-        ("running", "ğŸƒ"),
-        ("boxing", "ğŸ¥Š"),
-        ("wrestling", "ğŸ¤¼"),
-        ("climbing", "ğŸ§—"),
-        ("fishing", "ğŸ£"),
-        ("camping", "ğŸ•ï¸"),
+        ("âš½", &["soccer"]),
+        ("âš¾", &["baseball"]),
+        ("ğŸ¾", &["tennis"]),
+        ("â›³", &["golf"]),
+        ("ğŸ³", &["bowling"]),
+        ("â›·ï¸", &["skiing"]),
+        ("ğŸš´", &["cycling"]),
+        ("ğŸ¥Š", &["boxing"]),
+        ("ğŸ¤¼", &["wrestling"]),
+        ("ğŸ§—", &["climbing"]),
+        ("ğŸ•ï¸", &["camping"]),
         // Objects & Symbols
-        ("rocket", "ğŸš€"),
-        ("airplane", "âœˆï¸"),
-        ("car", "ğŸš—"),
-        ("bus", "ğŸšŒ"),
-        ("train", "ğŸš†"),
-        ("bicycle", "ğŸš²"),
-        ("boat", "â›µ"),
-        ("phone", "ğŸ“±"),
-        ("computer", "ğŸ’»"),
-        ("keyboard", "âŒ¨ï¸"),
-        ("camera", "ğŸ“·"),
-        ("book", "ğŸ“š"),
-        ("pen", "ğŸ–Šï¸"),
-        ("clock", "ğŸ•"),
-        ("money", "ğŸ’°"),
-        ("gem", "ğŸ’"),
-        ("trophy", "ğŸ†"),
-        ("medal", "ğŸ…"),
-        ("crown", "ğŸ‘‘"),
-        ("gift", "ğŸ"),
-        ("balloon", "ğŸˆ"),
-        ("party", "ğŸ‰"),
-        ("music", "ğŸµ"),
-        ("guitar", "ğŸ¸"),
-        ("drum", "ğŸ¥"),
-        ("dice", "ğŸ²"),
-        ("puzzle", "ğŸ§©"),
-        ("magnet", "ğŸ§²"),
-        ("lock", "ğŸ”’"),
-        ("key", "ğŸ”‘"),
-        ("hammer", "ğŸ”¨"),
-        ("shield", "ğŸ›¡ï¸"),
-        ("sword", "âš”ï¸"),
-        ("bomb", "ğŸ’£"),
-        ("flag", "ğŸ"),
-        ("warning", "âš ï¸"),
-        ("check", "âœ…"),
-        ("cross", "âŒ"),
-        ("question", "â“"),
-        ("exclamation", "â—"),
-        ("100", "ğŸ’¯"),
+        ("ğŸš€", &["rocket"]),
+        ("âœˆï¸", &["airplane"]),
+        ("ğŸš—", &["car"]),
+        ("ğŸšŒ", &["bus"]),
+        ("ğŸš†", &["train"]),
+        ("ğŸš²", &["bicycle"]),
+        ("â›µ", &["boat"]),
+        ("ğŸ“±", &["phone"]),
+        ("ğŸ’»", &["computer"]),
+        ("âŒ¨ï¸", &["keyboard"]),
+        ("ğŸ“·", &["camera"]),
+        ("ğŸ“š", &["book"]),
+        ("ğŸ–Šï¸", &["pen"]),
+        ("ğŸ’°", &["money"]),
+        ("ğŸ†", &["trophy"]),
+        ("ğŸ‘‘", &["crown"]),
+        ("ğŸ¥", &["drum"]),
+        ("ğŸ²", &["dice"]),
+        ("ğŸ§©", &["puzzle"]),
+        ("ğŸ§²", &["magnet"]),
+        ("ğŸ”’", &["lock"]),
+        ("ğŸ”‘", &["key"]),
+        ("ğŸ”¨", &["hammer"]),
+        ("ğŸ›¡ï¸", &["shield"]),
+        ("âš”ï¸", &["sword"]),
+        ("ğŸ’£", &["bomb"]),
+        ("âš ï¸", &["warning"]),
+        ("âœ…", &["check"]),
+        ("âŒ", &["cross"]),
+        ("â“", &["question"]),
+        ("â—", &["exclamation"]),
+        ("ğŸ’¯", &["100"]),
     ]
 });
 
+// ---------------------------------------------------------------------------
+// Locales
+// ---------------------------------------------------------------------------
+
+/// Supported locales for keyword matching. `q` is always matched against
+/// `Lang::En` as a fallback, regardless of the requested locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+enum Lang {
+    En,
+    Es,
+    De,
+    Fr,
+    Ja,
+    Zh,
+}
+
+impl Default for Lang {
+    fn default() -> Self {
+        Lang::En
+    }
+}
+
+/// Localized keyword tables, keyed by locale. `Lang::En` is not present here;
+/// it lives in `EMOJI_MAP` and is always consulted as the fallback table.
+static LOCALE_EMOJI_MAP: LazyLock<HashMap<Lang, Vec<(&str, &str)>>> = LazyLock::new(|| {
+    let mut map = HashMap::new();
+
+    map.insert(
+        Lang::Es,
+        vec![
+            ("gato", "🐈"),
+            ("perro", "🐕"),
+            ("pizza", "🍕"),
+            ("taco", "🌮"),
+            ("cafe", "☕"),
+            ("cerveza", "🍺"),
+            ("vino", "🍷"),
+            ("manzana", "🍎"),
+            ("platano", "🍌"),
+            ("sol", "☀️"),
+            ("luna", "🌙"),
+            ("estrella", "⭐"),
+            ("lluvia", "🌧️"),
+            ("fuego", "🔥"),
+            ("amor", "❤️"),
+            ("corazon", "❤️"),
+            ("risa", "😂"),
+            ("triste", "😢"),
+            ("libro", "📚"),
+            ("coche", "🚗"),
+            ("avion", "✈️"),
+            ("telefono", "📱"),
+            ("musica", "🎵"),
+            ("arbol", "🌳"),
+            ("flor", "🌸"),
+            ("pez", "🐟"),
+            ("pajaro", "🐦"),
+            ("leon", "🦁"),
+            ("oso", "🐻"),
+            ("conejo", "🐇"),
+        ],
+    );
+
+    map.insert(
+        Lang::De,
+        vec![
+            ("katze", "🐈"),
+            ("hund", "🐕"),
+            ("pizza", "🍕"),
+            ("kaffee", "☕"),
+            ("bier", "🍺"),
+            ("wein", "🍷"),
+            ("apfel", "🍎"),
+            ("sonne", "☀️"),
+            ("mond", "🌙"),
+            ("stern", "⭐"),
+            ("regen", "🌧️"),
+            ("feuer", "🔥"),
+            ("liebe", "❤️"),
+            ("herz", "❤️"),
+            ("lachen", "😂"),
+            ("traurig", "😢"),
+            ("buch", "📚"),
+            ("auto", "🚗"),
+            ("flugzeug", "✈️"),
+            ("telefon", "📱"),
+            ("musik", "🎵"),
+            ("baum", "🌳"),
+            ("blume", "🌸"),
+            ("fisch", "🐟"),
+            ("vogel", "🐦"),
+            ("loewe", "🦁"),
+            ("baer", "🐻"),
+            ("hase", "🐇"),
+        ],
+    );
+
+    map.insert(
+        Lang::Fr,
+        vec![
+            ("chat", "🐈"),
+            ("chien", "🐕"),
+            ("pizza", "🍕"),
+            ("cafe", "☕"),
+            ("biere", "🍺"),
+            ("vin", "🍷"),
+            ("pomme", "🍎"),
+            ("soleil", "☀️"),
+            ("lune", "🌙"),
+            ("etoile", "⭐"),
+            ("pluie", "🌧️"),
+            ("feu", "🔥"),
+            ("amour", "❤️"),
+            ("coeur", "❤️"),
+            ("rire", "😂"),
+            ("triste", "😢"),
+            ("livre", "📚"),
+            ("voiture", "🚗"),
+            ("avion", "✈️"),
+            ("telephone", "📱"),
+            ("musique", "🎵"),
+            ("arbre", "🌳"),
+            ("fleur", "🌸"),
+            ("poisson", "🐟"),
+            ("oiseau", "🐦"),
+            ("lion", "🦁"),
+            ("ours", "🐻"),
+            ("lapin", "🐇"),
+        ],
+    );
+
+    map.insert(
+        Lang::Ja,
+        vec![
+            ("neko", "🐈"),
+            ("inu", "🐕"),
+            ("pizza", "🍕"),
+            ("kohi", "☕"),
+            ("biiru", "🍺"),
+            ("ringo", "🍎"),
+            ("taiyo", "☀️"),
+            ("tsuki", "🌙"),
+            ("hoshi", "⭐"),
+            ("ame", "🌧️"),
+            ("hi", "🔥"),
+            ("ai", "❤️"),
+            ("warai", "😂"),
+            ("kanashii", "😢"),
+            ("hon", "📚"),
+            ("kuruma", "🚗"),
+            ("hikoki", "✈️"),
+            ("denwa", "📱"),
+            ("ongaku", "🎵"),
+            ("ki", "🌳"),
+            ("hana", "🌸"),
+            ("sakana", "🐟"),
+            ("tori", "🐦"),
+            ("raion", "🦁"),
+            ("kuma", "🐻"),
+            ("usagi", "🐇"),
+        ],
+    );
+
+    map.insert(
+        Lang::Zh,
+        vec![
+            ("mao", "🐈"),
+            ("gou", "🐕"),
+            ("pizza", "🍕"),
+            ("kafei", "☕"),
+            ("pijiu", "🍺"),
+            ("pingguo", "🍎"),
+            ("taiyang", "☀️"),
+            ("yueliang", "🌙"),
+            ("xingxing", "⭐"),
+            ("yu", "🌧️"),
+            ("huo", "🔥"),
+            ("ai", "❤️"),
+            ("xiao", "😂"),
+            ("beishang", "😢"),
+            ("shu", "📚"),
+            ("qiche", "🚗"),
+            ("feiji", "✈️"),
+            ("dianhua", "📱"),
+            ("yinyue", "🎵"),
+            ("shumu", "🌳"),
+            ("hua", "🌸"),
+            ("yuer", "🐟"),
+            ("niao", "🐦"),
+            ("shizi", "🦁"),
+            ("xiong", "🐻"),
+            ("tuzi", "🐇"),
+        ],
+    );
+
+    map
+});
+
 // ---------------------------------------------------------------------------
 // Request / Response types
 // ---------------------------------------------------------------------------
 
+/// Highest `limit` accepted by [`EmojiQuery`]; larger values are clamped.
+const MAX_LIMIT: usize = 25;
+
 #[derive(Deserialize, IntoParams)]
 struct EmojiQuery {
     /// Text to match against emoji keywords
     q: Option<String>,
+    /// Locale to search first (falls back to English); defaults to `en`
+    #[serde(default)]
+    lang: Lang,
+    /// Number of ranked candidates to return (default 1, max 25)
+    limit: Option<usize>,
+}
+
+/// How a keyword ended up matching the query, from most to least confident.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+enum MatchMethod {
+    Exact,
+    Substring,
+    Trigram,
+    Jaro,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -270,6 +464,8 @@ struct EmojiResponse {
     matched_keyword: String,
     /// Match confidence score (0.0â€“1.0)
     score: f64,
+    /// How the match was found
+    method: MatchMethod,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -278,37 +474,137 @@ struct ErrorResponse {
     error: String,
 }
 
+#[derive(Serialize, ToSchema)]
+struct KeywordsResponse {
+    /// The emoji that was looked up
+    emoji: String,
+    /// All keywords registered for this emoji
+    keywords: Vec<String>,
+}
+
 // ---------------------------------------------------------------------------
 // Matching logic
 // ---------------------------------------------------------------------------
 
-fn find_best_match(query: &str) -> (&str, &str, f64) {
-    let query_lower = query.to_lowercase();
+/// Splits an already-lowercased query on whitespace/punctuation into tokens, so
+/// multi-word queries like "ice cream cone" can be matched word-by-word.
+fn tokenize(query_lower: &str) -> Vec<&str> {
+    query_lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .collect()
+}
 
-    // Pass 1: exact match
-    for &(keyword, emoji) in EMOJI_MAP.iter() {
-        if keyword == query_lower {
-            return (keyword, emoji, 1.0);
-        }
+/// Character trigrams of `s`, padded with a leading/trailing space so word
+/// boundaries count towards the similarity score.
+fn trigrams(s: &str) -> std::collections::HashSet<String> {
+    let padded: Vec<char> = format!(" {s} ").chars().collect();
+    if padded.len() < 3 {
+        return std::collections::HashSet::from([padded.into_iter().collect()]);
     }
+    padded.windows(3).map(|w| w.iter().collect()).collect()
+}
 
-    // Pass 2: substring containment
-    for &(keyword, emoji) in EMOJI_MAP.iter() {
-        if keyword.contains(&query_lower) || query_lower.contains(keyword) {
-            return (keyword, emoji, 0.9);
-        }
+/// Dice coefficient `2*|A∩B| / (|A|+|B|)` over the trigram sets of `a` and `b`.
+fn dice_coefficient(a: &str, b: &str) -> f64 {
+    let set_a = trigrams(a);
+    let set_b = trigrams(b);
+    let overlap = set_a.intersection(&set_b).count();
+    2.0 * overlap as f64 / (set_a.len() + set_b.len()) as f64
+}
+
+/// Best Jaro-Winkler similarity between `keyword` and any single token of the
+/// query, so a compound query only needs one token to line up with a keyword.
+fn best_token_jaro_winkler(query_tokens: &[&str], keyword: &str) -> f64 {
+    query_tokens
+        .iter()
+        .map(|token| jaro_winkler(token, keyword))
+        .fold(0.0_f64, f64::max)
+}
+
+fn score_keyword(
+    query_lower: &str,
+    query_tokens: &[&str],
+    keyword: &str,
+) -> (f64, MatchMethod) {
+    if keyword == query_lower {
+        return (1.0, MatchMethod::Exact);
     }
+    if keyword.contains(query_lower) || query_lower.contains(keyword) {
+        return (0.9, MatchMethod::Substring);
+    }
+
+    let dice = dice_coefficient(query_lower, keyword);
+    let jw = best_token_jaro_winkler(query_tokens, keyword);
+
+    if dice >= jw {
+        (dice, MatchMethod::Trigram)
+    } else {
+        (jw, MatchMethod::Jaro)
+    }
+}
 
-    // Pass 3: Jaro-Winkler similarity
-    let mut best = ("", "", 0.0_f64);
-    for &(keyword, emoji) in EMOJI_MAP.iter() {
-        let score = jaro_winkler(&query_lower, keyword);
-        if score > best.2 {
-            best = (keyword, emoji, score);
+/// Score every alias of an emoji against the query and keep the best one.
+fn best_alias_match(
+    query_lower: &str,
+    query_tokens: &[&str],
+    aliases: &[&'static str],
+) -> (&'static str, f64, MatchMethod) {
+    aliases
+        .iter()
+        .map(|&keyword| {
+            let (score, method) = score_keyword(query_lower, query_tokens, keyword);
+            (keyword, score, method)
+        })
+        .fold(
+            ("", -1.0_f64, MatchMethod::Jaro),
+            |best, cur| if cur.1 > best.1 { cur } else { best },
+        )
+}
+
+/// Score every keyword the custom registry, requested locale, and English
+/// fallback have to offer, then sort descending, dedupe by emoji keeping the
+/// best score for each, and truncate to `limit`. The custom registry is
+/// scored first so ties with the static map resolve in its favor.
+fn rank_matches(
+    query: &str,
+    lang: Lang,
+    limit: usize,
+    custom: &CustomEmojiStore,
+) -> Vec<(String, String, f64, MatchMethod)> {
+    let query_lower = query.to_lowercase();
+    let query_tokens = tokenize(&query_lower);
+
+    let mut scored: Vec<(String, String, f64, MatchMethod)> = Vec::new();
+
+    {
+        let custom = custom.read().unwrap();
+        scored.extend(custom.iter().map(|(keyword, emoji)| {
+            let (score, method) = score_keyword(&query_lower, &query_tokens, keyword);
+            (keyword.clone(), emoji.clone(), score, method)
+        }));
+    }
+
+    if lang != Lang::En {
+        if let Some(table) = LOCALE_EMOJI_MAP.get(&lang) {
+            scored.extend(table.iter().map(|&(keyword, emoji)| {
+                let (score, method) = score_keyword(&query_lower, &query_tokens, keyword);
+                (keyword.to_string(), emoji.to_string(), score, method)
+            }));
         }
     }
+    scored.extend(EMOJI_MAP.iter().map(|&(emoji, aliases)| {
+        let (keyword, score, method) = best_alias_match(&query_lower, &query_tokens, aliases);
+        (keyword.to_string(), emoji.to_string(), score, method)
+    }));
+
+    scored.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap());
+
+    let mut seen_emoji = std::collections::HashSet::new();
+    scored.retain(|(_, emoji, _, _)| seen_emoji.insert(emoji.clone()));
 
-    best
+    scored.truncate(limit);
+    scored
 }
 
 // ---------------------------------------------------------------------------
@@ -320,11 +616,14 @@ fn find_best_match(query: &str) -> (&str, &str, f64) {
     path = "/emoji",
     params(EmojiQuery),
     responses(
-        (status = 200, description = "Matched emoji", body = EmojiResponse),
+        (status = 200, description = "Matched emoji (array when `limit` > 1)", body = EmojiResponse),
         (status = 400, description = "Missing query", body = ErrorResponse),
     )
 )]
-async fn get_emoji(Query(params): Query<EmojiQuery>) -> impl IntoResponse {
+async fn get_emoji(
+    State(state): State<AppState>,
+    Query(params): Query<EmojiQuery>,
+) -> impl IntoResponse {
     let query = match params.q {
         Some(q) if !q.trim().is_empty() => q,
         _ => {
@@ -336,14 +635,116 @@ async fn get_emoji(Query(params): Query<EmojiQuery>) -> impl IntoResponse {
         }
     };
 
-    let (keyword, emoji, score) = find_best_match(&query);
+    let limit = params.limit.unwrap_or(1).clamp(1, MAX_LIMIT);
+    let matches = rank_matches(&query, params.lang, limit, &state.custom_emoji);
 
-    Json(EmojiResponse {
-        emoji: emoji.to_string(),
-        matched_keyword: keyword.to_string(),
+    let to_response = |(keyword, emoji, score, method): &(String, String, f64, MatchMethod)| EmojiResponse {
+        emoji: emoji.clone(),
+        matched_keyword: keyword.clone(),
         score: (score * 1000.0).round() / 1000.0,
-    })
-    .into_response()
+        method: *method,
+    };
+
+    // `limit=1` keeps the original single-object shape for backwards
+    // compatibility; anything higher returns a ranked array.
+    if limit == 1 {
+        let response = matches.first().map(to_response).unwrap_or_else(|| {
+            to_response(&(String::new(), String::new(), 0.0, MatchMethod::Jaro))
+        });
+        Json(response).into_response()
+    } else {
+        let responses: Vec<EmojiResponse> = matches.iter().map(to_response).collect();
+        Json(responses).into_response()
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+struct CustomEmojiRequest {
+    /// Keyword that should resolve to this emoji
+    keyword: String,
+    /// The custom/shortcode emoji to register
+    emoji: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct CustomEmojiResponse {
+    /// The registered keyword
+    keyword: String,
+    /// The registered emoji
+    emoji: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/emoji",
+    request_body = CustomEmojiRequest,
+    responses(
+        (status = 201, description = "Custom emoji registered", body = CustomEmojiResponse),
+        (status = 400, description = "Empty keyword or emoji", body = ErrorResponse),
+    )
+)]
+async fn put_custom_emoji(
+    State(state): State<AppState>,
+    Json(body): Json<CustomEmojiRequest>,
+) -> impl IntoResponse {
+    let keyword = body.keyword.trim().to_lowercase();
+    let emoji = body.emoji.trim().to_string();
+
+    if keyword.is_empty() || emoji.is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "`keyword` and `emoji` are required"})),
+        )
+            .into_response();
+    }
+
+    state
+        .custom_emoji
+        .write()
+        .unwrap()
+        .put(keyword.clone(), emoji.clone());
+
+    (StatusCode::CREATED, Json(CustomEmojiResponse { keyword, emoji })).into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/keywords/{emoji}",
+    params(("emoji" = String, Path, description = "Literal emoji character to look up")),
+    responses(
+        (status = 200, description = "Keywords registered for the emoji", body = KeywordsResponse),
+        (status = 404, description = "No keywords registered for this emoji", body = ErrorResponse),
+    )
+)]
+async fn get_keywords(
+    State(state): State<AppState>,
+    Path(emoji): Path<String>,
+) -> impl IntoResponse {
+    let mut keywords: Vec<String> = Vec::new();
+
+    if let Some(&(_, aliases)) = EMOJI_MAP.iter().find(|&&(e, _)| e == emoji) {
+        keywords.extend(aliases.iter().map(|&k| k.to_string()));
+    }
+
+    keywords.extend(
+        state
+            .custom_emoji
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(_, v)| **v == emoji)
+            .map(|(k, _)| k.clone()),
+    );
+
+    if keywords.is_empty() {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "no keywords registered for this emoji"})),
+        )
+            .into_response();
+    }
+
+    Json(KeywordsResponse { emoji, keywords }).into_response()
 }
 
 // ---------------------------------------------------------------------------
@@ -352,16 +753,30 @@ async fn get_emoji(Query(params): Query<EmojiQuery>) -> impl IntoResponse {
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(get_emoji),
-    components(schemas(EmojiResponse, ErrorResponse))
+    paths(get_emoji, put_custom_emoji, get_keywords),
+    components(schemas(
+        EmojiResponse,
+        MatchMethod,
+        KeywordsResponse,
+        CustomEmojiRequest,
+        CustomEmojiResponse,
+        ErrorResponse
+    ))
 )]
 struct ApiDoc;
 
 #[tokio::main]
 async fn main() {
+    let state = AppState::new();
+
     let app = Router::new()
-        .route("/emoji", axum::routing::get(get_emoji))
-        .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()));
+        .route(
+            "/emoji",
+            axum::routing::get(get_emoji).post(put_custom_emoji),
+        )
+        .route("/keywords/:emoji", axum::routing::get(get_keywords))
+        .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
+        .with_state(state);
 
     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
     println!("Emoji API running on http://localhost:3000");